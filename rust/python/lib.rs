@@ -1,12 +1,18 @@
 use pyo3::prelude::*;
 
 mod example;
+mod time;
 
 pub use example::Example;
+pub use time::{parse_deadline, parse_duration};
 
 #[pymodule]
 fn temporalcache(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Example
     m.add_class::<Example>().unwrap();
+
+    // Time
+    m.add_function(wrap_pyfunction!(parse_duration, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_deadline, m)?)?;
     Ok(())
 }
@@ -0,0 +1,39 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use pyo3::exceptions::{PyOverflowError, PyValueError};
+use pyo3::prelude::*;
+
+use temporalcache::time;
+
+#[pyfunction]
+pub fn parse_duration(value: &str) -> PyResult<Duration> {
+    time::parse_duration(value).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+#[pyfunction]
+pub fn parse_deadline<'py>(py: Python<'py>, value: &str) -> PyResult<Bound<'py, PyAny>> {
+    let deadline = time::parse_deadline(value).map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    // pyo3's SystemTime conversion panics before 1970, so build the datetime
+    // from a signed offset from the epoch instead
+    let (sign, offset) = match deadline.duration_since(UNIX_EPOCH) {
+        Ok(offset) => (1, offset),
+        Err(e) => (-1, e.duration()),
+    };
+    let seconds = sign * i64::try_from(offset.as_secs()).unwrap_or(i64::MAX);
+    let micros = sign * i64::from(offset.subsec_micros());
+
+    let datetime = py.import("datetime")?;
+    let utc = datetime.getattr("timezone")?.getattr("utc")?;
+    let epoch = datetime
+        .getattr("datetime")?
+        .call1((1970, 1, 1, 0, 0, 0, 0, utc))?;
+    let delta = datetime.getattr("timedelta")?.call1((0, seconds, micros))?;
+    epoch.add(delta).map_err(|e| {
+        if e.is_instance_of::<PyOverflowError>(py) {
+            PyValueError::new_err(format!("invalid deadline {value:?}: out of range"))
+        } else {
+            e
+        }
+    })
+}
@@ -0,0 +1,90 @@
+use crate::CacheError;
+
+/// Parse a human-friendly capacity such as `"256MiB"` or `"2GB"` into bytes.
+///
+/// Decimal (`KB`, `MB`, `GB`, `TB`) and binary (`KiB`, `MiB`, `GiB`, `TiB`)
+/// suffixes are accepted case-insensitively, as is a bare `B` or no suffix
+/// at all for a plain byte count. Whitespace between the number and the
+/// suffix is allowed.
+pub fn parse_capacity(value: &str) -> Result<usize, CacheError> {
+    let input = value.trim();
+    let err = |token: &str| CacheError::InvalidCapacity {
+        input: value.to_string(),
+        token: token.to_string(),
+    };
+
+    let number_len = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    let (number, unit) = input.split_at(number_len);
+    if number.is_empty() {
+        return Err(err(input));
+    }
+    let unit = unit.trim_start();
+    let multiplier: u64 = match unit.to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "kb" => 1000,
+        "mb" => 1000 * 1000,
+        "gb" => 1000 * 1000 * 1000,
+        "tb" => 1000 * 1000 * 1000 * 1000,
+        "kib" => 1 << 10,
+        "mib" => 1 << 20,
+        "gib" => 1 << 30,
+        "tib" => 1 << 40,
+        _ => return Err(err(unit)),
+    };
+    number
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(multiplier))
+        .and_then(|bytes| usize::try_from(bytes).ok())
+        .ok_or_else(|| err(input))
+}
+
+/**********************************/
+#[cfg(test)]
+mod capacity_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_capacity_examples() {
+        let examples = [
+            ("0", 0),
+            ("512", 512),
+            ("512B", 512),
+            ("1KB", 1000),
+            ("1KiB", 1024),
+            ("256MiB", 268435456),
+            ("256 MiB", 268435456),
+            ("256mib", 268435456),
+            ("2MB", 2_000_000),
+            ("2GiB", 2147483648),
+            ("3GB", 3_000_000_000),
+            ("1TiB", 1 << 40),
+            (" 4KiB ", 4096),
+        ];
+        for (input, expected) in examples {
+            assert_eq!(parse_capacity(input), Ok(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_parse_capacity_errors() {
+        let examples = [
+            ("", ""),
+            ("MiB", "MiB"),
+            ("256XB", "XB"),
+            ("1.5GiB", ".5GiB"),
+            ("-1KB", "-1KB"),
+            ("99999999999999999999", "99999999999999999999"),
+            ("99999999999TiB", "99999999999TiB"),
+        ];
+        for (input, token) in examples {
+            let expected = CacheError::InvalidCapacity {
+                input: input.to_string(),
+                token: token.to_string(),
+            };
+            assert_eq!(parse_capacity(input), Err(expected), "{input}");
+        }
+    }
+}
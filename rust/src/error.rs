@@ -0,0 +1,45 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CacheError {
+    /// A human-readable duration (e.g. `"1h30m"`) could not be parsed
+    InvalidDuration { input: String, token: String },
+    /// An RFC 3339 timestamp could not be parsed
+    InvalidDeadline { input: String, token: String },
+    /// A human-readable capacity (e.g. `"256MiB"`) could not be parsed
+    InvalidCapacity { input: String, token: String },
+}
+
+impl fmt::Display for CacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheError::InvalidDuration { input, token } => {
+                write!(f, "invalid duration {input:?}: unexpected {token:?}")
+            }
+            CacheError::InvalidDeadline { input, token } => {
+                write!(f, "invalid deadline {input:?}: unexpected {token:?}")
+            }
+            CacheError::InvalidCapacity { input, token } => {
+                write!(f, "invalid capacity {input:?}: unexpected {token:?}")
+            }
+        }
+    }
+}
+
+impl Error for CacheError {}
+
+/**********************************/
+#[cfg(test)]
+mod error_tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let e = CacheError::InvalidDuration {
+            input: String::from("5x"),
+            token: String::from("x"),
+        };
+        assert_eq!(e.to_string(), "invalid duration \"5x\": unexpected \"x\"");
+    }
+}
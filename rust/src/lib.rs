@@ -1,3 +1,9 @@
+pub mod capacity;
+mod error;
+pub mod time;
+
+pub use error::CacheError;
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Example {
     pub stuff: String,
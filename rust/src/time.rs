@@ -0,0 +1,420 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::CacheError;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Parse a human-friendly duration such as `"5m"`, `"1h30m"` or `"1.5s"`.
+///
+/// A duration is one or more `<number><unit>` components, where the unit is
+/// one of `s`, `m`, `h`, `d` or `w` and the number may carry a fractional
+/// part (kept to nanosecond precision). Components are summed, so `"1h30m"`
+/// is ninety minutes.
+pub fn parse_duration(value: &str) -> Result<Duration, CacheError> {
+    let input = value.trim();
+    let err = |token: &str| CacheError::InvalidDuration {
+        input: value.to_string(),
+        token: token.to_string(),
+    };
+
+    if input.is_empty() {
+        return Err(err(""));
+    }
+
+    let mut total: u128 = 0;
+    let mut rest = input;
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(number_len);
+        let unit_len = tail
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_len);
+
+        if number.is_empty() {
+            // nothing numeric here, so report whatever run of characters
+            // we're stuck on instead
+            let stuck = if unit.is_empty() {
+                &tail[..tail.chars().next().map_or(0, char::len_utf8)]
+            } else {
+                unit
+            };
+            return Err(err(stuck));
+        }
+        let nanos = parse_decimal_nanos(number).ok_or_else(|| err(number))?;
+        let unit_secs: u128 = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 24 * 60 * 60,
+            "w" => 7 * 24 * 60 * 60,
+            "" => return Err(err(number)),
+            _ => return Err(err(unit)),
+        };
+        total = nanos
+            .checked_mul(unit_secs)
+            .and_then(|component| total.checked_add(component))
+            .ok_or_else(|| err(&rest[..number_len + unit_len]))?;
+        rest = tail;
+    }
+
+    let secs = u64::try_from(total / NANOS_PER_SEC).map_err(|_| err(input))?;
+    Ok(Duration::new(secs, (total % NANOS_PER_SEC) as u32))
+}
+
+/// Parse an RFC 3339 timestamp such as `"2025-07-01T16:00:00-04:00"`.
+///
+/// Fractional seconds are kept to nanosecond precision. A leap second
+/// (`:60`) maps onto the first instant of the following minute, since
+/// `SystemTime` has no representation for it.
+pub fn parse_deadline(value: &str) -> Result<SystemTime, CacheError> {
+    let input = value.trim();
+    let err = |token: &str| CacheError::InvalidDeadline {
+        input: value.to_string(),
+        token: token.to_string(),
+    };
+
+    let mut cursor = Cursor { rest: input };
+    let year = cursor.digits(4).ok_or_else(|| err(cursor.peek(4)))?;
+    cursor.expect("-").map_err(&err)?;
+    let month = cursor.field(2, 1, 12).map_err(&err)?;
+    cursor.expect("-").map_err(&err)?;
+    let day = cursor
+        .field(2, 1, days_in_month(year, month))
+        .map_err(&err)?;
+    cursor.expect_any(&["T", "t", " "]).map_err(&err)?;
+    let hour = cursor.field(2, 0, 23).map_err(&err)?;
+    cursor.expect(":").map_err(&err)?;
+    let minute = cursor.field(2, 0, 59).map_err(&err)?;
+    cursor.expect(":").map_err(&err)?;
+    let second = cursor.field(2, 0, 60).map_err(&err)?;
+
+    let mut nanos: u32 = 0;
+    if cursor.rest.starts_with('.') {
+        cursor.rest = &cursor.rest[1..];
+        let len = cursor
+            .rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(cursor.rest.len());
+        if len == 0 {
+            return Err(err(cursor.peek(1)));
+        }
+        let (fraction, tail) = cursor.rest.split_at(len);
+        nanos = fraction
+            .bytes()
+            .chain(std::iter::repeat(b'0'))
+            .take(9)
+            .fold(0, |acc, b| acc * 10 + u32::from(b - b'0'));
+        cursor.rest = tail;
+    }
+
+    let offset_secs: i64 = if cursor.expect_any(&["Z", "z"]).is_ok() {
+        0
+    } else {
+        let sign = match cursor.expect_any(&["+", "-"]) {
+            Ok("+") => 1,
+            Ok(_) => -1,
+            Err(token) => return Err(err(token)),
+        };
+        let offset_hour = cursor.field(2, 0, 23).map_err(&err)?;
+        cursor.expect(":").map_err(&err)?;
+        let offset_minute = cursor.field(2, 0, 59).map_err(&err)?;
+        sign * (i64::from(offset_hour) * 3600 + i64::from(offset_minute) * 60)
+    };
+    if !cursor.rest.is_empty() {
+        return Err(err(cursor.rest));
+    }
+
+    let secs = days_from_civil(i64::from(year), month, day) * 86400
+        + i64::from(hour) * 3600
+        + i64::from(minute) * 60
+        + i64::from(second)
+        - offset_secs;
+    let deadline = if secs >= 0 {
+        UNIX_EPOCH.checked_add(Duration::new(secs as u64, nanos))
+    } else {
+        UNIX_EPOCH
+            .checked_sub(Duration::from_secs(secs.unsigned_abs()))
+            .and_then(|t| t.checked_add(Duration::new(0, nanos)))
+    };
+    deadline.ok_or_else(|| err(input))
+}
+
+/// Parse `digits[.digits]` into nanoseconds, truncating past nanosecond
+/// precision
+fn parse_decimal_nanos(number: &str) -> Option<u128> {
+    let (whole, fraction) = match number.split_once('.') {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (number, ""),
+    };
+    if whole.is_empty() && fraction.is_empty() || fraction.contains('.') {
+        return None;
+    }
+    let whole: u128 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().ok()?
+    };
+    let fraction: u128 = fraction
+        .bytes()
+        .chain(std::iter::repeat(b'0'))
+        .take(9)
+        .fold(0, |acc, b| acc * 10 + u128::from(b - b'0'));
+    whole.checked_mul(NANOS_PER_SEC)?.checked_add(fraction)
+}
+
+struct Cursor<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    /// The next `n` characters (or fewer at the end of input), for error
+    /// reporting
+    fn peek(&self, n: usize) -> &'a str {
+        let end = self
+            .rest
+            .char_indices()
+            .nth(n)
+            .map_or(self.rest.len(), |(i, _)| i);
+        &self.rest[..end]
+    }
+
+    fn digits(&mut self, n: usize) -> Option<u32> {
+        let chunk = self.rest.get(..n)?;
+        if !chunk.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        self.rest = &self.rest[n..];
+        chunk.parse().ok()
+    }
+
+    fn field(&mut self, n: usize, min: u32, max: u32) -> Result<u32, &'a str> {
+        let token = self.peek(n);
+        match self.digits(n) {
+            Some(v) if (min..=max).contains(&v) => Ok(v),
+            _ => Err(token),
+        }
+    }
+
+    fn expect(&mut self, literal: &'static str) -> Result<(), &'a str> {
+        self.expect_any(&[literal]).map(|_| ())
+    }
+
+    fn expect_any(&mut self, literals: &[&'static str]) -> Result<&'static str, &'a str> {
+        for literal in literals {
+            if let Some(tail) = self.rest.strip_prefix(literal) {
+                self.rest = tail;
+                return Ok(literal);
+            }
+        }
+        Err(self.peek(1))
+    }
+}
+
+fn is_leap_year(year: u32) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * i64::from((month + 9) % 12) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/**********************************/
+#[cfg(test)]
+mod time_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn token_of(e: CacheError) -> String {
+        match e {
+            CacheError::InvalidDuration { token, .. }
+            | CacheError::InvalidDeadline { token, .. }
+            | CacheError::InvalidCapacity { token, .. } => token,
+        }
+    }
+
+    #[test]
+    fn test_parse_duration_examples() {
+        let examples = [
+            ("0s", Duration::ZERO),
+            ("5s", Duration::from_secs(5)),
+            ("5m", Duration::from_secs(5 * 60)),
+            ("2h", Duration::from_secs(2 * 3600)),
+            ("1d", Duration::from_secs(86400)),
+            ("1w", Duration::from_secs(7 * 86400)),
+            ("1h30m", Duration::from_secs(90 * 60)),
+            (
+                "1d2h3m4s",
+                Duration::from_secs(86400 + 2 * 3600 + 3 * 60 + 4),
+            ),
+            ("1.5s", Duration::from_millis(1500)),
+            ("0.000000001s", Duration::from_nanos(1)),
+            ("0.0000000019s", Duration::from_nanos(1)),
+            (".25s", Duration::from_millis(250)),
+            ("1.5h", Duration::from_secs(90 * 60)),
+            ("  10m ", Duration::from_secs(600)),
+        ];
+        for (input, expected) in examples {
+            assert_eq!(parse_duration(input), Ok(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_parse_duration_errors() {
+        let examples = [
+            ("", ""),
+            ("5", "5"),
+            ("5x", "x"),
+            ("5min", "min"),
+            ("1h30", "30"),
+            ("h", "h"),
+            ("1.2.3s", "1.2.3"),
+            (".s", "."),
+            ("1h 30m", " "),
+            ("-5s", "-"),
+        ];
+        for (input, token) in examples {
+            assert_eq!(
+                parse_duration(input).map_err(token_of),
+                Err(token.to_string()),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_duration_overflow() {
+        assert!(parse_duration("99999999999999999999w").is_err());
+    }
+
+    #[test]
+    fn test_parse_deadline_examples() {
+        let examples = [
+            ("1970-01-01T00:00:00Z", 0i64),
+            ("2025-07-01T16:00:00-04:00", 1751400000),
+            ("2025-07-01T20:00:00Z", 1751400000),
+            ("2025-07-01 20:00:00z", 1751400000),
+            ("2025-07-02T05:30:00+09:30", 1751400000),
+            ("2024-02-29T00:00:00Z", 1709164800),
+            ("2016-12-31T23:59:60Z", 1483228800),
+            ("1969-12-31T23:59:59Z", -1),
+        ];
+        for (input, secs) in examples {
+            let expected = if secs >= 0 {
+                UNIX_EPOCH + Duration::from_secs(secs as u64)
+            } else {
+                UNIX_EPOCH - Duration::from_secs(secs.unsigned_abs())
+            };
+            assert_eq!(parse_deadline(input), Ok(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_parse_deadline_fraction() {
+        assert_eq!(
+            parse_deadline("1970-01-01T00:00:01.5Z"),
+            Ok(UNIX_EPOCH + Duration::from_millis(1500))
+        );
+        assert_eq!(
+            parse_deadline("1969-12-31T23:59:59.25Z"),
+            Ok(UNIX_EPOCH - Duration::from_millis(750))
+        );
+    }
+
+    #[test]
+    fn test_parse_deadline_errors() {
+        let examples = [
+            ("", ""),
+            ("2025-13-01T00:00:00Z", "13"),
+            ("2025-02-29T00:00:00Z", "29"),
+            ("2025-07-01X00:00:00Z", "X"),
+            ("2025-07-01T24:00:00Z", "24"),
+            ("2025-07-01T00:00:00", ""),
+            ("2025-07-01T00:00:00+0400", "0"),
+            ("2025-07-01T00:00:00.Z", "Z"),
+            ("2025-07-01T00:00:00Zjunk", "junk"),
+            ("25-07-01T00:00:00Z", "25-0"),
+        ];
+        for (input, token) in examples {
+            assert_eq!(
+                parse_deadline(input).map_err(token_of),
+                Err(token.to_string()),
+                "{input}"
+            );
+        }
+    }
+
+    /// Inverse of `days_from_civil`, only needed to format test inputs
+    fn civil_from_days(days: i64) -> (i64, u32, u32) {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days.rem_euclid(146097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        (year_of_era + era * 400 + i64::from(month <= 2), month, day)
+    }
+
+    proptest! {
+        #[test]
+        fn prop_duration_components_round_trip(h in 0u64..10_000, m in 0u64..60, s in 0u64..60, nanos in 0u32..1_000_000_000) {
+            let input = format!("{h}h{m}m{s}.{nanos:09}s");
+            prop_assert_eq!(parse_duration(&input), Ok(Duration::new(h * 3600 + m * 60 + s, nanos)));
+        }
+
+        #[test]
+        fn prop_duration_is_sum_of_components(a in 0u64..1_000_000, b in 0u64..1_000_000) {
+            let joined = parse_duration(&format!("{a}m{b}s")).unwrap();
+            prop_assert_eq!(joined, parse_duration(&format!("{a}m")).unwrap() + parse_duration(&format!("{b}s")).unwrap());
+        }
+
+        #[test]
+        fn prop_duration_never_panics(input in "\\PC*") {
+            let _ = parse_duration(&input);
+        }
+
+        #[test]
+        fn prop_deadline_round_trip(secs in 0i64..253_402_300_800, offset_minutes in -1439i64..1440) {
+            // format `secs` as local time in the given offset, then parse it back
+            let local = secs + offset_minutes * 60;
+            prop_assume!((0..253_402_300_800).contains(&local));
+            let (year, month, day) = civil_from_days(local.div_euclid(86400));
+            let time_of_day = local.rem_euclid(86400);
+            let sign = if offset_minutes < 0 { '-' } else { '+' };
+            let input = format!(
+                "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}{sign}{:02}:{:02}",
+                time_of_day / 3600,
+                time_of_day / 60 % 60,
+                time_of_day % 60,
+                offset_minutes.abs() / 60,
+                offset_minutes.abs() % 60,
+            );
+            prop_assert_eq!(parse_deadline(&input), Ok(UNIX_EPOCH + Duration::from_secs(secs as u64)));
+        }
+
+        #[test]
+        fn prop_deadline_never_panics(input in "\\PC*") {
+            let _ = parse_deadline(&input);
+        }
+    }
+}
@@ -7,8 +7,11 @@
 #
 from .expire import daily as expire_daily, expire, hourly as expire_hourly, minutely as expire_minutely, monthly as expire_monthly
 from .interval import daily, hourly, interval, minutely, monthly
+from .temporalcache import parse_deadline, parse_duration
 from .utils import (
     TEMPORAL_CACHE_GLOBAL_DISABLE,
+    BoundaryRules,
+    BusinessCalendar,
     StorageBase,
     TCException,
     disable,
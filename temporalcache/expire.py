@@ -15,7 +15,7 @@ from tzlocal import get_localzone
 
 from . import utils
 from .persistent_lru_cache import persistent_lru_cache
-from .utils import TCException, should_expire
+from .utils import BoundaryRules, TCException, next_boundary, should_expire, single_flight
 
 
 def expire(
@@ -30,12 +30,46 @@ def expire(
     maxsize=128,
     persistent="",
     custom=None,
+    lock=False,
+    lock_timeout=None,
+    calendar=None,
+    boundary_rules=None,
     **kwargs,
 ):
     """Expires all entries in the cache @ whole number time
 
     for example, @expire(0, 30, 16) will expire the cache at 4:30pm every day
+
+    with lock=True, concurrent misses on the same arguments only compute once (see `utils.single_flight`)
+
+    with calendar, the cache instead expires at each calendar.next_occurrence(after) (see `utils.BusinessCalendar`);
+    calendar may also be a plain callable taking and returning a datetime
+
+    when only second/minute/hour are given, boundary_rules (see `utils.BoundaryRules`) decides how
+    boundaries on DST gaps, repeated wall times and the exact boundary instant are treated
     """
+    if lock_timeout is not None and not lock:
+        raise TCException("lock_timeout requires lock=True")
+
+    if lock_timeout is not None and lock_timeout < 0:
+        raise TCException("lock_timeout must be >= 0")
+
+    if calendar is not None and any(
+        (
+            second is not None,
+            minute is not None,
+            hour is not None,
+            day is not None,
+            day_of_week is not None,
+            week is not None,
+            month is not None,
+        )
+    ):
+        raise TCException("calendar cannot be combined with second/minute/hour/day/day_of_week/week/month")
+
+    if boundary_rules is not None and (calendar is not None or any((day is not None, day_of_week is not None, week is not None, month is not None))):
+        raise TCException("boundary_rules only applies to second/minute/hour")
+
     if not any(
         (
             second is not None,
@@ -86,8 +120,20 @@ def expire(
         except pytz.UnknownTimeZoneError:
             tz = datetime.tzinfo(tz)
 
+    boundary_rules = boundary_rules or BoundaryRules()
+    if calendar is not None:
+        next_deadline = getattr(calendar, "next_occurrence", calendar)
+    elif all((day is None, day_of_week is None, week is None, month is None)):
+
+        def next_deadline(after):
+            return next_boundary(after, second, minute, hour, tz=tz, rules=boundary_rules)
+
+    else:
+        next_deadline = None
+
     def _wrapper(foo):
         last = datetime.datetime.now(tz=tz)
+        deadline = next_deadline(last) if next_deadline is not None else None
 
         if custom:
             foo = custom(**kwargs)(foo)
@@ -96,20 +142,31 @@ def expire(
         else:
             foo = lru_cache(maxsize)(foo)
 
+        call = single_flight(foo, lock_timeout) if lock else foo
+
         @wraps(foo)
         def _wrapped_foo(*args, **kwargs):
-            nonlocal last
+            nonlocal last, deadline
 
             now = datetime.datetime.now(tz=tz)
-            if should_expire(last, now, second, minute, hour, day, day_of_week, week, month) or utils.TEMPORAL_CACHE_GLOBAL_DISABLE:
+            if next_deadline is not None:
+                expired = now >= deadline if boundary_rules.inclusive else now > deadline
+                if expired:
+                    deadline = next_deadline(now)
+            else:
+                expired = should_expire(last, now, second, minute, hour, day, day_of_week, week, month)
+            if expired or utils.TEMPORAL_CACHE_GLOBAL_DISABLE:
                 foo.cache_clear()
             last = now
 
             args = tuple([frozendict(arg) if isinstance(arg, dict) else tuple(arg) if isinstance(arg, list) else arg for arg in args])
             kwargs = {k: frozendict(v) if isinstance(v, dict) else tuple(v) if isinstance(v, list) else v for k, v in kwargs.items()}
 
-            return foo(*args, **kwargs)
+            return call(*args, **kwargs)
 
+        _wrapped_foo.cache_clear = foo.cache_clear
+        if hasattr(foo, "cache_info"):
+            _wrapped_foo.cache_info = foo.cache_info
         return _wrapped_foo
 
     return _wrapper
@@ -6,22 +6,58 @@
 # the Apache License 2.0.  The full license can be found in the LICENSE file.
 #
 import datetime
+import time
 from functools import lru_cache, wraps
 
 from frozendict import frozendict
 
 from . import utils
 from .persistent_lru_cache import persistent_lru_cache
-from .utils import calc
+from .utils import TCException, calc, single_flight
+
+
+def interval(
+    seconds=0,
+    minutes=0,
+    hours=0,
+    days=0,
+    weeks=0,
+    months=0,
+    years=0,
+    maxsize=128,
+    persistent="",
+    custom=None,
+    lock=False,
+    lock_timeout=None,
+    clock="monotonic",
+    **kwargs,
+):
+    """Expires all entries in the cache every interval
+
+    with lock=True, concurrent misses on the same arguments only compute once (see `utils.single_flight`)
+
+    with clock="monotonic", the interval is measured on the monotonic clock, so stepping the
+    system clock (e.g. by NTP) neither extends nor expires entries; clock="wall" uses the system clock
+    """
+    if lock_timeout is not None and not lock:
+        raise TCException("lock_timeout requires lock=True")
+
+    if lock_timeout is not None and lock_timeout < 0:
+        raise TCException("lock_timeout must be >= 0")
+
+    if clock not in ("monotonic", "wall"):
+        raise TCException('clock must be "monotonic" or "wall"')
 
-
-def interval(seconds=0, minutes=0, hours=0, days=0, weeks=0, months=0, years=0, maxsize=128, persistent="", custom=None, **kwargs):
-    """Expires all entries in the cache every interval"""
     if not any((seconds, minutes, hours, days, weeks, months, years)):
         seconds = 1
 
+    def _now():
+        if clock == "monotonic":
+            return datetime.timedelta(seconds=time.monotonic())
+        return datetime.datetime.now()  # noqa: DTZ005
+
     def _wrapper(foo):
-        last = datetime.datetime.now()  # noqa: DTZ005
+        last = _now()
 
         if custom:
             foo = custom(**kwargs)(foo)
@@ -30,19 +66,24 @@ def interval(seconds=0, minutes=0, hours=0, days=0, weeks=0, months=0, years=0,
         else:
             foo = lru_cache(maxsize)(foo)
 
+        call = single_flight(foo, lock_timeout) if lock else foo
+
         @wraps(foo)
         def _wrapped_foo(*args, **kwargs):
             nonlocal last
 
-            now = datetime.datetime.now()  # noqa: DTZ005
+            now = _now()
             if (now - last).total_seconds() > calc(seconds, minutes, hours, days, weeks, months, years) or utils.TEMPORAL_CACHE_GLOBAL_DISABLE:
                 foo.cache_clear()
             last = now
 
             args = tuple([frozendict(arg) if isinstance(arg, dict) else tuple(arg) if isinstance(arg, list) else arg for arg in args])
             kwargs = {k: frozendict(v) if isinstance(v, dict) else tuple(v) if isinstance(v, list) else v for k, v in kwargs.items()}
-            return foo(*args, **kwargs)
+            return call(*args, **kwargs)
 
+        _wrapped_foo.cache_clear = foo.cache_clear
+        if hasattr(foo, "cache_info"):
+            _wrapped_foo.cache_info = foo.cache_info
         return _wrapped_foo
 
     return _wrapper
@@ -398,6 +398,177 @@ class TestExpire:
         except TCException:
             pass
 
+    def test_lock(self):
+        import threading
+
+        from temporalcache import expire
+
+        self._now = datetime.datetime(2018, 1, 1, 1, 1, 0)  # noqa: DTZ001
+        self._delay = datetime.timedelta(seconds=0)
+        calls = []
+        barrier = threading.Barrier(32)
+
+        @expire(1, lock=True)
+        def foo(test):
+            calls.append(test)
+            time.sleep(0.1)
+            return len(calls)
+
+        results = []
+
+        def run():
+            barrier.wait()
+            results.append(foo("a"))
+
+        threads = [threading.Thread(target=run) for _ in range(32)]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+
+        assert calls == ["a"]
+        assert results == [1] * 32
+
+    def test_lock_checks(self):
+        from random import random
+
+        from temporalcache import TCException, expire
+
+        for kwargs in ({"lock_timeout": 1}, {"lock": True, "lock_timeout": -0.5}):
+            try:
+
+                @expire(1, **kwargs)
+                def foo():
+                    return random()
+
+                raise AssertionError  # pragma: no cover
+            except TCException:
+                pass
+
+    def test_calendar(self):
+        from random import random
+
+        from temporalcache import BusinessCalendar, expire
+
+        # 2018-01-05 is a Friday, and Monday 2018-01-08 is a holiday
+        self._now = datetime.datetime(2018, 1, 5, 10, 0, 0)  # noqa: DTZ001
+        self._delay = datetime.timedelta(seconds=0)
+
+        @expire(calendar=BusinessCalendar(datetime.time(9, 30), holidays=[datetime.date(2018, 1, 8)]))
+        def foo():
+            return random()
+
+        x = foo()
+        assert x == foo()
+
+        # no open over the weekend or on the holiday
+        self._delay = datetime.timedelta(days=3, hours=1)
+        assert x == foo()
+
+        # Tuesday's open
+        self._delay = datetime.timedelta(days=3, hours=23, minutes=31)
+        y = foo()
+        assert x != y
+        assert y == foo()
+
+        # Wednesday's open
+        self._delay = datetime.timedelta(days=4, hours=23, minutes=31)
+        assert y != foo()
+
+    def test_calendar_callable(self):
+        from random import random
+
+        from temporalcache import expire
+
+        self._now = datetime.datetime(2018, 1, 1, 1, 1, 0)  # noqa: DTZ001
+        self._delay = datetime.timedelta(seconds=0)
+
+        @expire(calendar=lambda after: after + datetime.timedelta(hours=1))
+        def foo():
+            return random()
+
+        x = foo()
+        self._delay = datetime.timedelta(minutes=59)
+        assert x == foo()
+        self._delay = datetime.timedelta(hours=1)
+        assert x != foo()
+
+    def test_calendar_with_fields(self):
+        from random import random
+
+        from temporalcache import BusinessCalendar, TCException, expire
+
+        try:
+
+            @expire(hour=16, calendar=BusinessCalendar())
+            def foo():
+                return random()
+
+            raise AssertionError  # pragma: no cover
+        except TCException:
+            pass
+
+    def test_dst_gap(self):
+        from random import random
+
+        from temporalcache import expire
+
+        # keep the clock in UTC so the delays below are absolute
+        self._now = datetime.datetime(2018, 3, 10, 17, 0, tzinfo=datetime.timezone.utc)
+        self._delay = datetime.timedelta(seconds=0)
+
+        @expire(minute=30, hour=2, tz="America/New_York")
+        def foo():
+            return random()
+
+        x = foo()
+
+        # 01:59 EST, just before the 02:30 that never happens
+        self._delay = datetime.timedelta(hours=13, minutes=59)
+        assert x == foo()
+
+        # 03:00 EDT, the first instant after the gap
+        self._delay = datetime.timedelta(hours=14)
+        assert x != foo()
+
+    def test_dst_repeated_hour(self):
+        from random import random
+
+        from temporalcache import expire
+
+        # 01:00 EST, the start of the second 01:00-01:59 on 2018-11-04
+        self._now = datetime.datetime(2018, 11, 4, 6, 0, tzinfo=datetime.timezone.utc)
+        self._delay = datetime.timedelta(seconds=0)
+
+        @expire(minute=30, tz="America/New_York")
+        def foo():
+            return random()
+
+        x = foo()
+
+        # 01:29 EST, 01:30 EDT has already passed
+        self._delay = datetime.timedelta(minutes=29)
+        assert x == foo()
+
+        # 01:30 EST still expires
+        self._delay = datetime.timedelta(minutes=30)
+        assert x != foo()
+
+    def test_boundary_rules_checks(self):
+        from random import random
+
+        from temporalcache import BoundaryRules, TCException, expire
+
+        try:
+
+            @expire(day=1, boundary_rules=BoundaryRules())
+            def foo():
+                return random()
+
+            raise AssertionError  # pragma: no cover
+        except TCException:
+            pass
+
 
 class TestExpireTZ:
     def test_tzexpire(self):
@@ -6,25 +6,33 @@
 # the Apache License 2.0.  The full license can be found in the LICENSE file.
 #
 import datetime
+import time
 
 
 class TestInterval:
     def setup_method(self):
         self._olddatetime = datetime.datetime
+        self._oldmonotonic = time.monotonic
         _now = datetime.datetime.now()  # noqa: DTZ005
+        _monotonic = time.monotonic()
+
+        # _step moves the system clock without time passing, like an NTP step
+        self._step = datetime.timedelta(seconds=0)
 
         class NewDateTime(datetime.datetime):
             @classmethod
             def now(cls):
-                ret = _now + self._delay
+                ret = _now + self._delay + self._step
                 print(_now)
                 print(ret)
                 return ret
 
         datetime.datetime = NewDateTime
+        time.monotonic = lambda: _monotonic + self._delay.total_seconds()
 
     def teardown_method(self):
         datetime.datetime = self._olddatetime
+        time.monotonic = self._oldmonotonic
 
     def test_blank(self):
         from random import random
@@ -217,3 +225,178 @@ class TestInterval:
         print("checking cache expired")
         assert x != foo()
         print("success")
+
+    def test_lock(self):
+        import threading
+        import time
+
+        from temporalcache import interval
+
+        self._delay = datetime.timedelta(seconds=0)
+        calls = []
+        barrier = threading.Barrier(32)
+
+        @interval(seconds=5, lock=True)
+        def foo(test):
+            calls.append(test)
+            time.sleep(0.1)
+            return len(calls)
+
+        results = []
+
+        def run():
+            barrier.wait()
+            results.append(foo("a"))
+
+        threads = [threading.Thread(target=run) for _ in range(32)]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+
+        assert calls == ["a"]
+        assert results == [1] * 32
+
+    def test_lock_recursive(self):
+        from temporalcache import interval
+
+        self._delay = datetime.timedelta(seconds=0)
+
+        @interval(seconds=5, lock=True)
+        def fib(n):
+            return n if n < 2 else fib(n - 1) + fib(n - 2)
+
+        assert fib(20) == 6765
+
+    def test_lock_timeout(self):
+        import threading
+
+        from temporalcache import interval
+
+        self._delay = datetime.timedelta(seconds=0)
+        calls = []
+        started = threading.Event()
+        release = threading.Event()
+
+        @interval(seconds=5, lock=True, lock_timeout=0.05)
+        def foo(test):
+            calls.append(test)
+            if len(calls) == 1:
+                started.set()
+                release.wait(5)
+            return len(calls)
+
+        slow = threading.Thread(target=foo, args=("a",))
+        slow.start()
+        started.wait(5)
+
+        # the first call is still holding the lock, so this one gives up waiting
+        # and computes on its own
+        assert foo("a") == 2
+        release.set()
+        slow.join()
+        assert calls == ["a", "a"]
+
+    def test_maxsize(self):
+        from temporalcache import interval
+
+        self._delay = datetime.timedelta(seconds=0)
+        calls = []
+
+        @interval(seconds=5, maxsize=2)
+        def foo(test):
+            calls.append(test)
+            return test
+
+        foo("a")
+        foo("b")
+        foo("a")
+        # "b" is the least recently used, so it is evicted
+        foo("c")
+        assert foo.cache_info().currsize == 2
+        assert foo.cache_info().maxsize == 2
+
+        foo("a")
+        foo("c")
+        assert calls == ["a", "b", "c"]
+        foo("b")
+        assert calls == ["a", "b", "c", "b"]
+
+        # expiring drops every entry, not just the least recently used ones
+        self._delay = datetime.timedelta(seconds=6)
+        foo("a")
+        assert foo.cache_info().currsize == 1
+        assert calls == ["a", "b", "c", "b", "a"]
+
+        foo.cache_clear()
+        assert foo.cache_info().currsize == 0
+
+    def test_lock_checks(self):
+        from random import random
+
+        from temporalcache import TCException, interval
+
+        for kwargs in ({"lock_timeout": 1}, {"lock": True, "lock_timeout": -0.5}):
+            try:
+
+                @interval(seconds=5, **kwargs)
+                def foo():
+                    return random()
+
+                raise AssertionError  # pragma: no cover
+            except TCException:
+                pass
+
+    def test_clock_step(self):
+        from random import random
+
+        from temporalcache import interval
+
+        self._delay = datetime.timedelta(seconds=0)
+
+        @interval(seconds=10)
+        def foo():
+            return random()
+
+        x = foo()
+
+        # stepping the clock back doesn't stall expiry
+        self._step = datetime.timedelta(hours=-1)
+        self._delay = datetime.timedelta(seconds=11)
+        y = foo()
+        assert x != y
+
+        # stepping the clock forward doesn't expire everything
+        self._step = datetime.timedelta(hours=1)
+        assert y == foo()
+
+    def test_clock_wall(self):
+        from random import random
+
+        from temporalcache import interval
+
+        self._delay = datetime.timedelta(seconds=0)
+
+        @interval(seconds=10, clock="wall")
+        def foo():
+            return random()
+
+        x = foo()
+
+        self._step = datetime.timedelta(hours=1)
+        assert x != foo()
+
+    def test_clock_checks(self):
+        from random import random
+
+        from temporalcache import TCException, interval
+
+        try:
+
+            @interval(seconds=10, clock="system")
+            def foo():
+                return random()
+
+            raise AssertionError  # pragma: no cover
+        except TCException:
+            pass
@@ -0,0 +1,57 @@
+# *****************************************************************************
+#
+# Copyright (c) 2021, the temporal-cache authors.
+#
+# This file is part of the temporal-cache library, distributed under the terms of
+# the Apache License 2.0.  The full license can be found in the LICENSE file.
+#
+import datetime
+
+
+class TestTime:
+    def test_parse_duration(self):
+        from temporalcache import parse_duration
+
+        assert parse_duration("1h30m") == datetime.timedelta(minutes=90)
+        assert parse_duration("1.5s") == datetime.timedelta(seconds=1.5)
+
+    def test_parse_duration_error(self):
+        from temporalcache import parse_duration
+
+        try:
+            parse_duration("5x")
+            raise AssertionError  # pragma: no cover
+        except ValueError as e:
+            assert str(e) == 'invalid duration "5x": unexpected "x"'
+
+    def test_parse_deadline(self):
+        from temporalcache import parse_deadline
+
+        expected = datetime.datetime(2025, 7, 1, 20, 0, tzinfo=datetime.timezone.utc)
+        assert parse_deadline("2025-07-01T16:00:00-04:00") == expected
+
+    def test_parse_deadline_error(self):
+        from temporalcache import parse_deadline
+
+        try:
+            parse_deadline("2025-13-01T00:00:00Z")
+            raise AssertionError  # pragma: no cover
+        except ValueError as e:
+            assert str(e) == 'invalid deadline "2025-13-01T00:00:00Z": unexpected "13"'
+
+    def test_parse_deadline_before_epoch(self):
+        from temporalcache import parse_deadline
+
+        expected = datetime.datetime(1969, 12, 31, 23, 59, 59, 500000, tzinfo=datetime.timezone.utc)
+        assert parse_deadline("1969-12-31T23:59:59.5Z") == expected
+        assert parse_deadline("0001-01-01T00:00:00Z") == datetime.datetime.min.replace(tzinfo=datetime.timezone.utc)
+
+    def test_parse_deadline_out_of_range(self):
+        from temporalcache import parse_deadline
+
+        for value in ("9999-12-31T23:59:59-23:59", "0001-01-01T00:00:00+01:00"):
+            try:
+                parse_deadline(value)
+                raise AssertionError  # pragma: no cover
+            except ValueError as e:
+                assert str(e) == f'invalid deadline "{value}": out of range'
@@ -135,3 +135,132 @@ class TestUtils:
         assert should_expire(now, now, 0) is False
         assert should_expire(now, next_second, 0) is False
         assert should_expire(now, next_secondp, 0)
+
+    def test_business_calendar(self):
+        from temporalcache import BusinessCalendar
+
+        # 2018-01-05 is a Friday, and Monday 2018-01-08 is a holiday
+        calendar = BusinessCalendar(datetime.time(9, 30), holidays=[datetime.date(2018, 1, 8)])
+        friday_open = datetime.datetime(2018, 1, 5, 9, 30)  # noqa: DTZ001
+        tuesday_open = datetime.datetime(2018, 1, 9, 9, 30)  # noqa: DTZ001
+        assert calendar.next_occurrence(datetime.datetime(2018, 1, 5, 8, 0)) == friday_open  # noqa: DTZ001
+        # strictly after, so an instant exactly at the open rolls over
+        assert calendar.next_occurrence(friday_open) == tuesday_open
+        assert calendar.next_occurrence(datetime.datetime(2018, 1, 6, 12, 0)) == tuesday_open  # noqa: DTZ001
+        assert calendar.next_occurrence(datetime.datetime(2018, 1, 8, 8, 0)) == tuesday_open  # noqa: DTZ001
+
+    def test_business_calendar_weekend_and_holiday_types(self):
+        from temporalcache import BusinessCalendar
+
+        calendar = BusinessCalendar(holidays=["2018-01-01"])
+        # Saturday rolls over the weekend, and the Monday holiday, to Tuesday
+        assert calendar.next_occurrence(datetime.datetime(2017, 12, 30, 12, 0)) == datetime.datetime(2018, 1, 2)  # noqa: DTZ001
+
+        calendar = BusinessCalendar(holidays=[datetime.datetime(2018, 1, 1, 12, 0)])  # noqa: DTZ001
+        assert calendar.next_occurrence(datetime.datetime(2017, 12, 30, 12, 0)) == datetime.datetime(2018, 1, 2)  # noqa: DTZ001
+
+        weekend = BusinessCalendar(weekdays=(5, 6))
+        assert weekend.next_occurrence(datetime.datetime(2018, 1, 1)) == datetime.datetime(2018, 1, 6)  # noqa: DTZ001
+
+    def test_business_calendar_tz(self):
+        import pytz
+
+        from temporalcache import BusinessCalendar
+
+        tz = pytz.timezone("America/New_York")
+        calendar = BusinessCalendar(datetime.time(9, 30))
+        # the Monday after the spring-forward weekend opens at -04:00, not -05:00
+        after = tz.localize(datetime.datetime(2018, 3, 9, 16, 0))  # noqa: DTZ001
+        assert calendar.next_occurrence(after) == tz.localize(datetime.datetime(2018, 3, 12, 9, 30))  # noqa: DTZ001
+        assert calendar.next_occurrence(after).utcoffset() == datetime.timedelta(hours=-4)
+
+    def test_business_calendar_no_weekdays(self):
+        from temporalcache import BusinessCalendar, TCException
+
+        for weekdays in ((), (7,), (0, -1)):
+            try:
+                BusinessCalendar(weekdays=weekdays)
+                raise AssertionError  # pragma: no cover
+            except TCException:
+                pass
+
+    def test_boundary_dst_gap(self):
+        import pytz
+
+        from temporalcache.utils import BoundaryRules, next_boundary
+
+        tz = pytz.timezone("America/New_York")
+        utc = datetime.timezone.utc
+        # 02:30 does not exist on 2018-03-11, clocks jump from 02:00 EST to 03:00 EDT
+        after = datetime.datetime(2018, 3, 10, 17, 0, tzinfo=utc)
+        boundary = next_boundary(after, minute=30, hour=2, tz=tz)
+        assert boundary == datetime.datetime(2018, 3, 11, 7, 0, tzinfo=utc)
+        assert boundary.astimezone(tz).replace(tzinfo=None) == datetime.datetime(2018, 3, 11, 3, 0)  # noqa: DTZ001
+
+        skipped = next_boundary(after, minute=30, hour=2, tz=tz, rules=BoundaryRules(gap="skip"))
+        assert skipped == datetime.datetime(2018, 3, 12, 6, 30, tzinfo=utc)
+
+    def test_boundary_dst_ambiguous(self):
+        import pytz
+
+        from temporalcache.utils import BoundaryRules, crossed_boundary, next_boundary
+
+        tz = pytz.timezone("America/New_York")
+        utc = datetime.timezone.utc
+        # 01:30 happens twice on 2018-11-04, first in EDT and then in EST
+        after = datetime.datetime(2018, 11, 3, 16, 0, tzinfo=utc)
+        # compare in UTC, times in a repeated hour never equal times in another zone
+        earlier = next_boundary(after, minute=30, hour=1, tz=tz)
+        assert earlier.astimezone(utc) == datetime.datetime(2018, 11, 4, 5, 30, tzinfo=utc)
+        later = next_boundary(after, minute=30, hour=1, tz=tz, rules=BoundaryRules(ambiguous="later"))
+        assert later.astimezone(utc) == datetime.datetime(2018, 11, 4, 6, 30, tzinfo=utc)
+
+        # hourly and minutely boundaries follow elapsed time, so they fire in both copies of the repeated hour
+        first = next_boundary(datetime.datetime(2018, 11, 4, 5, 0, tzinfo=utc), minute=30, tz=tz)
+        assert first.astimezone(utc) == datetime.datetime(2018, 11, 4, 5, 30, tzinfo=utc)
+        assert next_boundary(first, minute=30, tz=tz).astimezone(utc) == datetime.datetime(2018, 11, 4, 6, 30, tzinfo=utc)
+        repeated = datetime.datetime(2018, 11, 4, 6, 0, 30, tzinfo=utc)
+        assert next_boundary(repeated, second=0, tz=tz).astimezone(utc) == datetime.datetime(2018, 11, 4, 6, 1, tzinfo=utc)
+        assert crossed_boundary(repeated, repeated + datetime.timedelta(minutes=40), second=0, tz=tz)
+
+    def test_boundary_skipped_day(self):
+        import pytz
+
+        from temporalcache.utils import BoundaryRules, next_boundary
+
+        tz = pytz.timezone("Pacific/Apia")
+        utc = datetime.timezone.utc
+        # Samoa skipped 2011-12-30 entirely, going from 23:59:59 on the 29th (-10:00)
+        # to 00:00 on the 31st (+14:00)
+        after = datetime.datetime(2011, 12, 29, 23, 0, tzinfo=utc)
+        boundary = next_boundary(after, hour=12, tz=tz)
+        assert boundary == datetime.datetime(2011, 12, 30, 10, 0, tzinfo=utc)
+        assert boundary.astimezone(tz).replace(tzinfo=None) == datetime.datetime(2011, 12, 31, 0, 0)  # noqa: DTZ001
+
+        skipped = next_boundary(after, hour=12, tz=tz, rules=BoundaryRules(gap="skip"))
+        assert skipped == datetime.datetime(2011, 12, 30, 22, 0, tzinfo=utc)
+
+    def test_boundary_inclusive(self):
+        from temporalcache.utils import BoundaryRules, crossed_boundary, next_boundary
+
+        midnight = datetime.datetime(2018, 1, 2)  # noqa: DTZ001
+        later = midnight + datetime.timedelta(seconds=1)
+        # by default a value cached exactly at midnight belongs to the new day
+        assert next_boundary(midnight, hour=0) == midnight + datetime.timedelta(days=1)
+        assert crossed_boundary(midnight, later, hour=0) is False
+        assert crossed_boundary(midnight - datetime.timedelta(seconds=1), midnight, hour=0)
+
+        exclusive = BoundaryRules(inclusive=False)
+        assert next_boundary(midnight, hour=0, rules=exclusive) == midnight
+        assert crossed_boundary(midnight, later, hour=0, rules=exclusive)
+        assert crossed_boundary(midnight - datetime.timedelta(seconds=1), midnight, hour=0, rules=exclusive) is False
+
+    def test_boundary_rules_checks(self):
+        from temporalcache.utils import BoundaryRules, TCException
+
+        for kwargs in ({"gap": "previous"}, {"ambiguous": "first"}):
+            try:
+                BoundaryRules(**kwargs)
+                raise AssertionError  # pragma: no cover
+            except TCException:
+                pass
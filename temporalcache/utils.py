@@ -5,9 +5,11 @@
 # This file is part of the temporal-cache library, distributed under the terms of
 # the Apache License 2.0.  The full license can be found in the LICENSE file.
 #
+import datetime
 import os
 from abc import ABCMeta, abstractmethod
 from functools import lru_cache, wraps
+from threading import Lock, RLock
 
 TEMPORAL_CACHE_GLOBAL_DISABLE = bool(os.environ.get("TEMPORAL_CACHE_DISABLE"))
 
@@ -41,6 +43,186 @@ class StorageBase(metaclass=ABCMeta):
         return _wrapper
 
 
+def single_flight(foo, timeout=None):
+    """Serialize calls to the cached function `foo` per set of arguments
+
+    Only one thread at a time calls `foo` for a given set of arguments, so when
+    an entry is missing the first thread computes it and the others wait and then
+    read it from the cache. A thread that has waited `timeout` seconds stops
+    waiting and calls `foo` itself. Locks are reentrant, so recursive calls from
+    the same thread do not deadlock.
+    """
+    guard = Lock()
+    locks = {}  # key -> [lock, number of threads using it]
+
+    @wraps(foo)
+    def _wrapper(*args, **kwargs):
+        key = (args, frozenset(kwargs.items()))
+        with guard:
+            entry = locks.setdefault(key, [RLock(), 0])
+            entry[1] += 1
+        try:
+            acquired = entry[0].acquire(timeout=-1 if timeout is None else timeout)
+            try:
+                return foo(*args, **kwargs)
+            finally:
+                if acquired:
+                    entry[0].release()
+        finally:
+            with guard:
+                entry[1] -= 1
+                if not entry[1]:
+                    del locks[key]
+
+    return _wrapper
+
+
+def _to_date(day):
+    if isinstance(day, str):
+        return datetime.date.fromisoformat(day)
+    if isinstance(day, datetime.datetime):
+        return day.date()
+    return day
+
+
+class BusinessCalendar:
+    """Calendar whose occurrences are the daily open of each business day
+
+    open - datetime.time the business day opens at
+    holidays - dates (or datetimes, or ISO "YYYY-MM-DD" strings) that are not business days
+    weekdays - days of the week that are business days, Monday is 0
+
+    for example, BusinessCalendar(datetime.time(9, 30), holidays=["2025-07-04"]) for
+    NYSE-style opens, paired with @expire(calendar=..., tz="America/New_York")
+    """
+
+    def __init__(self, open=datetime.time(0), holidays=(), weekdays=(0, 1, 2, 3, 4)):
+        if not weekdays:
+            raise TCException("weekdays must not be empty")
+        if any(weekday not in range(7) for weekday in weekdays):
+            raise TCException("weekdays must be >= 0, < 7")
+        self.open = open
+        self.holidays = frozenset(_to_date(day) for day in holidays)
+        self.weekdays = frozenset(weekdays)
+
+    def is_business_day(self, day):
+        return day.weekday() in self.weekdays and day not in self.holidays
+
+    def next_occurrence(self, after):
+        """first business day open strictly after `after`, in `after`'s timezone"""
+        day = after.date()
+        while True:
+            if self.is_business_day(day):
+                candidate = datetime.datetime.combine(day, self.open)
+                if after.tzinfo is not None:
+                    # pytz zones need localize to pick the right offset
+                    candidate = after.tzinfo.localize(candidate) if hasattr(after.tzinfo, "localize") else candidate.replace(tzinfo=after.tzinfo)
+                if candidate > after:
+                    return candidate
+            day += datetime.timedelta(days=1)
+
+
+class BoundaryRules:
+    """How @expire places second/minute/hour boundaries that land on awkward instants
+
+    inclusive - with True, a boundary belongs to the period it starts, so a value cached exactly
+                at midnight is kept until the next midnight; with False, it belongs to the period
+                it ends, so that value expires as soon as time moves past midnight
+    gap - when a daily boundary's wall time is skipped by a DST change (e.g. 02:30 on a
+          spring-forward day), "next" expires at the first valid instant after the gap and
+          "skip" ignores that day's boundary
+    ambiguous - when a daily boundary's wall time happens twice (e.g. 01:30 on a fall-back day),
+                "earlier" uses the first occurrence and "later" the second
+
+    gap and ambiguous only apply to daily (hour) boundaries; hourly and minutely boundaries
+    follow elapsed time, so they fire at every instant whose local minute/second match
+
+    boundaries are computed on POSIX time, which has no leap seconds, so a leap second never
+    moves or duplicates a boundary
+    """
+
+    def __init__(self, inclusive=True, gap="next", ambiguous="earlier"):
+        if gap not in ("next", "skip"):
+            raise TCException('gap must be "next" or "skip"')
+        if ambiguous not in ("earlier", "later"):
+            raise TCException('ambiguous must be "earlier" or "later"')
+        self.inclusive = inclusive
+        self.gap = gap
+        self.ambiguous = ambiguous
+
+
+def _resolve(wall, tz, rules):
+    """the instant naive wall-clock time `wall` happens at in `tz`, or None if `rules` skip it"""
+    if tz is None:
+        return wall
+    utc = datetime.timezone.utc
+
+    def local(instant):
+        return instant.astimezone(tz)
+
+    # offsets either side of any transition near `wall`
+    before = local((wall - datetime.timedelta(days=2)).replace(tzinfo=utc)).utcoffset()
+    after = local((wall + datetime.timedelta(days=2)).replace(tzinfo=utc)).utcoffset()
+    valid = set()
+    for offset in (before, after):
+        instant = (wall - offset).replace(tzinfo=utc)
+        if local(instant).replace(tzinfo=None) == wall:
+            valid.add(instant)
+    valid = sorted(valid)
+    if valid:
+        return local(valid[0] if rules.ambiguous == "earlier" else valid[-1])
+    if rules.gap == "skip":
+        return None
+
+    # `wall` falls in a gap, so find the transition that ends it to the second
+    start = (wall - after).replace(tzinfo=utc)
+    lo, hi = 0, int(((wall - before).replace(tzinfo=utc) - start).total_seconds())
+    while hi - lo > 1:
+        mid = (lo + hi) // 2
+        if local(start + datetime.timedelta(seconds=mid)).utcoffset() == after:
+            hi = mid
+        else:
+            lo = mid
+    return local(start + datetime.timedelta(seconds=hi))
+
+
+def next_boundary(after, second=None, minute=None, hour=None, tz=None, rules=None):
+    """first boundary matching second/minute/hour after `after`, placed according to `rules`
+
+    the coarsest field given sets the period (hour - daily, minute - hourly, second - every minute),
+    and finer fields that aren't given are 0. an aware `after` is read in `tz` (or its own timezone)
+    """
+    rules = rules or BoundaryRules()
+    tz = (tz or after.tzinfo) if after.tzinfo is not None else None
+
+    def due(boundary):
+        return boundary > after if rules.inclusive else boundary >= after
+
+    if hour is None:
+        # offsets are whole minutes, so step through real minutes and keep the first whose
+        # local minute matches; repeated and skipped wall times need no special handling
+        start = after.astimezone(datetime.timezone.utc) if tz is not None else after
+        boundary = start.replace(second=second or 0, microsecond=0) - datetime.timedelta(minutes=1)
+        while not due(boundary) or (minute is not None and (boundary.astimezone(tz) if tz is not None else boundary).minute != minute):
+            boundary += datetime.timedelta(minutes=1)
+        return boundary.astimezone(tz) if tz is not None else boundary
+
+    wall = after.astimezone(tz).replace(tzinfo=None) if tz is not None else after
+    candidate = wall.replace(hour=hour, minute=minute or 0, second=second or 0, microsecond=0) - datetime.timedelta(days=1)
+    while True:
+        boundary = _resolve(candidate, tz, rules)
+        if boundary is not None and due(boundary):
+            return boundary
+        candidate += datetime.timedelta(days=1)
+
+
+def crossed_boundary(last, now, second=None, minute=None, hour=None, tz=None, rules=None):
+    """has a second/minute/hour boundary passed between `last` and `now`? (see `BoundaryRules`)"""
+    rules = rules or BoundaryRules()
+    boundary = next_boundary(last, second, minute, hour, tz, rules)
+    return boundary <= now if rules.inclusive else boundary < now
+
+
 def _base(last, now, lap, offset, multiple, attr):
     """
     last - last datetime